@@ -5,6 +5,7 @@ import { ServiceDefinition } from '../types';
 import * as preNormalizationRules from './preNormalization';
 import * as preCompositionRules from './preComposition';
 import * as postCompositionRules from './postComposition';
+import { duplicateServiceName } from './preComposition/duplicateServiceName';
 
 const preNormalizationValidators = Object.values(preNormalizationRules);
 
@@ -27,7 +28,7 @@ const preCompositionValidators = Object.values(preCompositionRules);
 export const validateServicesBeforeComposition = (
   services: ServiceDefinition[],
 ) => {
-  const warningsOrErrors: GraphQLError[] = [];
+  const warningsOrErrors: GraphQLError[] = duplicateServiceName(services);
 
   for (const serviceDefinition of services) {
     for (const validator of preCompositionValidators) {
@@ -0,0 +1,74 @@
+import {
+  duplicateServiceName as validateDuplicateServiceName,
+} from '../duplicateServiceName';
+import { gql } from 'apollo-federation-integration-testsuite';
+
+describe('duplicateServiceName', () => {
+  it('does not error when every service has a unique name', () => {
+    const serviceList = [
+      {
+        typeDefs: gql`
+          type Query {
+            users: [String]
+          }
+        `,
+        name: 'serviceA',
+      },
+      {
+        typeDefs: gql`
+          extend type Query {
+            movies: [String]
+          }
+        `,
+        name: 'serviceB',
+      },
+    ];
+
+    const warnings = validateDuplicateServiceName(serviceList);
+    expect(warnings).toEqual([]);
+  });
+
+  it('errors once for each name shared by multiple services', () => {
+    const serviceList = [
+      {
+        typeDefs: gql`
+          type Query {
+            users: [String]
+          }
+        `,
+        name: 'serviceA',
+      },
+      {
+        typeDefs: gql`
+          extend type Query {
+            movies: [String]
+          }
+        `,
+        name: 'serviceB',
+      },
+      {
+        typeDefs: gql`
+          extend type Query {
+            people: [String]
+          }
+        `,
+        name: 'serviceA',
+      },
+      {
+        typeDefs: gql`
+          extend type Query {
+            books: [String]
+          }
+        `,
+        name: 'serviceA',
+      },
+    ];
+
+    const warnings = validateDuplicateServiceName(serviceList);
+    expect(warnings).toHaveLength(1);
+    expect(warnings[0].extensions?.code).toEqual('DUPLICATE_SERVICE_NAME');
+    expect(warnings[0].message).toEqual(
+      '[serviceA] -> There are multiple services named `serviceA`. Each service must have a unique name.',
+    );
+  });
+});
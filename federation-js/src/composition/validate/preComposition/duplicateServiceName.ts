@@ -0,0 +1,33 @@
+import { GraphQLError } from 'graphql';
+import { ServiceDefinition } from '../../types';
+
+import { errorWithCode } from '../../utils';
+
+/**
+ * Error when multiple services in the service list share a name.
+ *
+ * This runs against the whole service list, so unlike the other validators
+ * in this directory it isn't exported from the (per-service) index.
+ */
+export const duplicateServiceName = (serviceList: ServiceDefinition[]) => {
+  const errors: GraphQLError[] = [];
+
+  // keep track of every service name and error once for each duplicated name
+  const seen = new Set<string>();
+  const reported = new Set<string>();
+
+  for (const { name } of serviceList) {
+    if (seen.has(name) && !reported.has(name)) {
+      errors.push(
+        errorWithCode(
+          'DUPLICATE_SERVICE_NAME',
+          `[${name}] -> There are multiple services named \`${name}\`. Each service must have a unique name.`,
+        ),
+      );
+      reported.add(name);
+    }
+    seen.add(name);
+  }
+
+  return errors;
+};